@@ -0,0 +1,178 @@
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use crate::ignore::IgnoreMatcher;
+use crate::rules::Rule;
+use crate::{scan_binary, scan_text, Finding};
+
+/// Does `path` look like an archive `scan_archive` knows how to open?
+pub(crate) fn is_archive(path: &Path) -> bool {
+    let name = path.to_string_lossy().to_lowercase();
+    name.ends_with(".tar") || name.ends_with(".tar.gz") || name.ends_with(".tgz") || name.ends_with(".zip")
+}
+
+/// Scan a `.tar`, `.tar.gz`/`.tgz`, or `.zip` archive entry by entry.
+/// Findings carry a path like `archive.tar!path/inside/entry.env`.
+pub(crate) fn scan_archive(path: &Path, patterns: &[Rule], ignores: &IgnoreMatcher, min_string_len: usize) -> Vec<Finding> {
+    let name = path.to_string_lossy().to_lowercase();
+    if name.ends_with(".tar") {
+        scan_tar(path, patterns, ignores, false, min_string_len)
+    } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+        scan_tar(path, patterns, ignores, true, min_string_len)
+    } else if name.ends_with(".zip") {
+        scan_zip(path, patterns, ignores, min_string_len)
+    } else {
+        Vec::new()
+    }
+}
+
+/// Scan one entry's bytes, falling back to `scan_binary` for non-UTF-8 content.
+fn scan_entry(label: &str, data: &[u8], patterns: &[Rule], min_string_len: usize) -> Vec<Finding> {
+    match std::str::from_utf8(data) {
+        Ok(text) => scan_text(label, text, patterns),
+        Err(_) => scan_binary(label, data, patterns, min_string_len),
+    }
+}
+
+fn scan_tar(path: &Path, patterns: &[Rule], ignores: &IgnoreMatcher, gzip: bool, min_string_len: usize) -> Vec<Finding> {
+    let file = match File::open(path) {
+        Ok(f) => f,
+        Err(_) => return Vec::new(),
+    };
+    let label = path.to_string_lossy().to_string();
+    if gzip {
+        let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(file));
+        scan_tar_entries(&mut archive, &label, patterns, ignores, min_string_len)
+    } else {
+        let mut archive = tar::Archive::new(file);
+        scan_tar_entries(&mut archive, &label, patterns, ignores, min_string_len)
+    }
+}
+
+fn scan_tar_entries<R: Read>(archive: &mut tar::Archive<R>, label: &str, patterns: &[Rule], ignores: &IgnoreMatcher, min_string_len: usize) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let entries = match archive.entries() {
+        Ok(e) => e,
+        Err(_) => return findings,
+    };
+    for entry in entries.flatten() {
+        let mut entry = entry;
+        let entry_path = match entry.path() {
+            Ok(p) => p.to_string_lossy().replace('\\', "/"),
+            Err(_) => continue,
+        };
+        if ignores.is_ignored(&entry_path) { continue }
+        let mut buf = Vec::new();
+        if entry.read_to_end(&mut buf).is_err() { continue }
+        findings.extend(scan_entry(&format!("{}!{}", label, entry_path), &buf, patterns, min_string_len));
+    }
+    findings
+}
+
+fn scan_zip(path: &Path, patterns: &[Rule], ignores: &IgnoreMatcher, min_string_len: usize) -> Vec<Finding> {
+    let file = match File::open(path) {
+        Ok(f) => f,
+        Err(_) => return Vec::new(),
+    };
+    let mut archive = match zip::ZipArchive::new(file) {
+        Ok(a) => a,
+        Err(_) => return Vec::new(),
+    };
+    let label = path.to_string_lossy().to_string();
+
+    let mut findings = Vec::new();
+    for i in 0..archive.len() {
+        let mut zf = match archive.by_index(i) {
+            Ok(f) => f,
+            Err(_) => continue,
+        };
+        if zf.is_dir() { continue }
+        let entry_path = zf.name().replace('\\', "/");
+        if ignores.is_ignored(&entry_path) { continue }
+        let mut buf = Vec::new();
+        if zf.read_to_end(&mut buf).is_err() { continue }
+        findings.extend(scan_entry(&format!("{}!{}", label, entry_path), &buf, patterns, min_string_len));
+    }
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::parse_rules;
+    use std::io::{Cursor, Write};
+
+    fn test_patterns() -> Vec<Rule> {
+        parse_rules("Secret::sk_live_[0-9a-zA-Z]+::test,0.9")
+    }
+
+    fn build_tar(entries: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut builder = tar::Builder::new(Vec::new());
+        for (name, data) in entries {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(data.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, name, *data).unwrap();
+        }
+        builder.into_inner().unwrap()
+    }
+
+    fn build_zip(entries: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let options = zip::write::FileOptions::default();
+        for (name, data) in entries {
+            zip.start_file(*name, options).unwrap();
+            zip.write_all(data).unwrap();
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn scan_tar_finds_secret_in_text_entry() {
+        let dir = std::env::temp_dir().join(format!("lss-test-{}.tar", std::process::id()));
+        let bytes = build_tar(&[("config/.env", b"API_KEY=sk_live_abc123")]);
+        std::fs::write(&dir, bytes).unwrap();
+        let findings = scan_archive(&dir, &test_patterns(), &IgnoreMatcher::new(), 6);
+        std::fs::remove_file(&dir).ok();
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].path.ends_with(".tar!config/.env"));
+    }
+
+    #[test]
+    fn scan_zip_finds_secret_in_text_entry() {
+        let dir = std::env::temp_dir().join(format!("lss-test-{}.zip", std::process::id()));
+        let bytes = build_zip(&[("secrets.txt", b"token=sk_live_zzz999")]);
+        std::fs::write(&dir, bytes).unwrap();
+        let findings = scan_archive(&dir, &test_patterns(), &IgnoreMatcher::new(), 6);
+        std::fs::remove_file(&dir).ok();
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].path.ends_with(".zip!secrets.txt"));
+    }
+
+    #[test]
+    fn scan_tar_falls_back_to_binary_for_non_utf8_entry() {
+        let dir = std::env::temp_dir().join(format!("lss-test-bin-{}.tar", std::process::id()));
+        let mut data = vec![0xffu8, 0xfe, 0x00];
+        data.extend_from_slice(b"sk_live_binary0001");
+        let bytes = build_tar(&[("keystore.jks", &data)]);
+        std::fs::write(&dir, bytes).unwrap();
+        let findings = scan_archive(&dir, &test_patterns(), &IgnoreMatcher::new(), 6);
+        std::fs::remove_file(&dir).ok();
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].path.ends_with(".tar!keystore.jks"));
+    }
+
+    #[test]
+    fn scan_archive_respects_ignores() {
+        let dir = std::env::temp_dir().join(format!("lss-test-ignore-{}.tar", std::process::id()));
+        let bytes = build_tar(&[("vendor/secret.env", b"token=sk_live_shouldskip")]);
+        std::fs::write(&dir, bytes).unwrap();
+        let mut ignores = IgnoreMatcher::new();
+        ignores.add("vendor/");
+        let findings = scan_archive(&dir, &test_patterns(), &ignores, 6);
+        std::fs::remove_file(&dir).ok();
+        assert!(findings.is_empty());
+    }
+}
@@ -0,0 +1,180 @@
+use regex::Regex;
+
+/// A single compiled ignore pattern plus whether it is a `!`-negation override.
+#[derive(Debug, Clone)]
+struct IgnorePattern {
+    regex: Regex,
+    negate: bool,
+}
+
+/// An ordered set of gitignore-style glob patterns compiled into anchored
+/// regexes (`?`→`[^/]`, `*`→`[^/]*`, `**`→`.*`), evaluated against paths
+/// relative to the scan root. Patterns are applied in the order they were
+/// added, so a later `!pattern` can un-ignore an earlier match, mirroring
+/// `.gitignore` semantics.
+#[derive(Debug, Clone, Default)]
+pub struct IgnoreMatcher {
+    patterns: Vec<IgnorePattern>,
+}
+
+impl IgnoreMatcher {
+    pub fn new() -> Self {
+        IgnoreMatcher { patterns: Vec::new() }
+    }
+
+    /// Add one pattern, as read from a `.lssignore` line or config `ignore` entry.
+    /// Blank lines and `#`-comments are ignored.
+    pub fn add(&mut self, raw: &str) {
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return;
+        }
+        let (negate, pat) = match line.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, line),
+        };
+        if let Some(regex) = compile_glob(pat) {
+            self.patterns.push(IgnorePattern { regex, negate });
+        }
+    }
+
+    /// Add many patterns in order.
+    pub fn extend<I, S>(&mut self, raws: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for r in raws {
+            self.add(r.as_ref());
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    /// Evaluate `rel_path` (relative to the scan root, using `/` separators)
+    /// against all patterns in order. The last matching pattern wins, so a
+    /// trailing negation overrides an earlier positive match.
+    pub fn is_ignored(&self, rel_path: &str) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+        let mut ignored = false;
+        for p in &self.patterns {
+            if p.regex.is_match(rel_path) {
+                ignored = !p.negate;
+            }
+        }
+        ignored
+    }
+}
+
+/// Translate one gitignore-style glob into an anchored regex.
+///
+/// - a leading `/` anchors the pattern to the scan root instead of matching
+///   at any directory depth
+/// - a trailing `/` means the pattern only matches a directory, so it also
+///   matches anything underneath it
+/// - `?` matches a single non-separator character, `*` matches a run of
+///   non-separator characters, and `**` matches across separators
+pub(crate) fn compile_glob(pat: &str) -> Option<Regex> {
+    let anchored_start = pat.starts_with('/');
+    let pat = pat.strip_prefix('/').unwrap_or(pat);
+    let dir_only = pat.ends_with('/');
+    let pat = pat.strip_suffix('/').unwrap_or(pat);
+
+    let mut re = String::from("^");
+    if !anchored_start {
+        re.push_str("(.*/)?");
+    }
+
+    let chars: Vec<char> = pat.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '*' => {
+                if chars.get(i + 1) == Some(&'*') {
+                    if chars.get(i + 2) == Some(&'/') {
+                        // `**/` matches zero or more whole directories, so it
+                        // also matches when the rest of the pattern sits at
+                        // the scan root with no parent directory at all.
+                        re.push_str("(?:.*/)?");
+                        i += 3;
+                    } else {
+                        re.push_str(".*");
+                        i += 2;
+                    }
+                } else {
+                    re.push_str("[^/]*");
+                    i += 1;
+                }
+            }
+            '?' => {
+                re.push_str("[^/]");
+                i += 1;
+            }
+            c => {
+                if "\\.+()|[]{}^$".contains(c) {
+                    re.push('\\');
+                }
+                re.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    if dir_only {
+        re.push_str("/.*$");
+    } else {
+        re.push('$');
+    }
+
+    Regex::new(&re).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn substring_false_positive_is_fixed() {
+        let mut m = IgnoreMatcher::new();
+        m.add("test");
+        assert!(!m.is_ignored("src/latest.rs"));
+        assert!(m.is_ignored("test"));
+    }
+
+    #[test]
+    fn star_glob_matches_extension() {
+        let mut m = IgnoreMatcher::new();
+        m.add("*.pem");
+        assert!(m.is_ignored("certs/server.pem"));
+        assert!(!m.is_ignored("certs/server.pem.bak"));
+    }
+
+    #[test]
+    fn double_star_matches_nested_dir() {
+        let mut m = IgnoreMatcher::new();
+        m.add("**/node_modules/");
+        assert!(m.is_ignored("node_modules/foo/index.js"));
+        assert!(m.is_ignored("a/b/node_modules/foo.js"));
+    }
+
+    #[test]
+    fn leading_slash_anchors_to_root() {
+        let mut m = IgnoreMatcher::new();
+        m.add("/build");
+        assert!(m.is_ignored("build"));
+        assert!(!m.is_ignored("sub/build"));
+    }
+
+    #[test]
+    fn negation_overrides_earlier_match() {
+        let mut m = IgnoreMatcher::new();
+        m.add("*.pem");
+        m.add("!keep.pem");
+        assert!(!m.is_ignored("keep.pem"));
+        assert!(m.is_ignored("drop.pem"));
+    }
+}
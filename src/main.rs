@@ -8,7 +8,13 @@ use git2::Repository;
 use serde::Deserialize;
 use std::collections::{HashSet, HashMap};
 use std::io::Read;
+mod archive;
+mod ignore;
 mod rules;
+mod strings;
+mod types;
+mod watch;
+use ignore::IgnoreMatcher;
 use lss::shannon_entropy;
 
 #[derive(clap::Subcommand, Debug)]
@@ -29,6 +35,27 @@ enum RulesCmd {
     },
 }
 
+#[derive(clap::Subcommand, Debug)]
+enum TypesCmd {
+    /// List file type definitions, optionally filter by name substring
+    List {
+        /// optional name substring filter
+        query: Option<String>,
+        /// output json
+        #[arg(long)]
+        json: bool,
+        /// page number (1-based)
+        #[arg(long, default_value_t = 1)]
+        page: usize,
+        /// items per page
+        #[arg(long, default_value_t = 20)]
+        per_page: usize,
+        /// Load additional custom type definitions (`name:glob,glob` per line) from a file
+        #[arg(long)]
+        types_file: Option<PathBuf>,
+    },
+}
+
 #[derive(clap::Subcommand, Debug)]
 enum Command {
     /// Scan a path for secrets (default)
@@ -64,10 +91,76 @@ enum Command {
         /// Minimum rule confidence (0.0-1.0)
         #[arg(long)]
         min_confidence: Option<f64>,
+
+        /// Only scan git history starting after this revision (exclusive)
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Only scan git history up to this revision (inclusive), default HEAD
+        #[arg(long)]
+        until: Option<String>,
+
+        /// Minimum length of a printable-ASCII run extracted from binary content
+        #[arg(long, default_value_t = 6)]
+        min_string_len: usize,
+
+        /// Only scan files matching this file type (repeatable); see `lss types list`
+        #[arg(long = "type")]
+        r#type: Vec<String>,
+
+        /// Exclude files matching this file type (repeatable)
+        #[arg(long = "type-not")]
+        type_not: Vec<String>,
+
+        /// Load custom type definitions (`name:glob,glob` per line) from a file
+        #[arg(long)]
+        types_file: Option<PathBuf>,
+    },
+
+    /// Watch a path and rescan incrementally as files change
+    Watch {
+        /// Path to watch
+        #[arg(short, long, default_value = ".")]
+        path: PathBuf,
+
+        /// Output format: human or json
+        #[arg(short, long, default_value = "human")]
+        format: String,
+
+        /// Override entropy threshold
+        #[arg(long)]
+        entropy_threshold: Option<f64>,
+
+        /// Additional ignore file (one pattern per line)
+        #[arg(long)]
+        ignore_file: Option<PathBuf>,
+
+        /// Load extra regex rules from a file (format: Name::Regex per line)
+        #[arg(long)]
+        rules_file: Option<PathBuf>,
+
+        /// Include only findings that have any of these comma-separated tags
+        #[arg(long)]
+        include_tags: Option<String>,
+
+        /// Exclude findings that have any of these comma-separated tags
+        #[arg(long)]
+        exclude_tags: Option<String>,
+
+        /// Minimum rule confidence (0.0-1.0)
+        #[arg(long)]
+        min_confidence: Option<f64>,
+
+        /// Minimum length of a printable-ASCII run extracted from binary content
+        #[arg(long, default_value_t = 6)]
+        min_string_len: usize,
     },
 
     /// Rules subcommands
     Rules { #[command(subcommand)] cmd: RulesCmd },
+
+    /// File type subcommands
+    Types { #[command(subcommand)] cmd: TypesCmd },
 }
 
 #[derive(clap::Parser, Debug)]
@@ -109,18 +202,42 @@ struct Cli {
     #[arg(long)]
     min_confidence: Option<f64>,
 
+    /// Only scan git history starting after this revision (when using `--scan` shorthand)
+    #[arg(long)]
+    since: Option<String>,
+
+    /// Only scan git history up to this revision (when using `--scan` shorthand)
+    #[arg(long)]
+    until: Option<String>,
+
+    /// Minimum length of a printable-ASCII run extracted from binary content (when using `--scan` shorthand)
+    #[arg(long, default_value_t = 6)]
+    min_string_len: usize,
+
+    /// Only scan files matching this file type (when using `--scan` shorthand, repeatable)
+    #[arg(long = "type")]
+    r#type: Vec<String>,
+
+    /// Exclude files matching this file type (when using `--scan` shorthand, repeatable)
+    #[arg(long = "type-not")]
+    type_not: Vec<String>,
+
+    /// Load custom type definitions (when using `--scan` shorthand)
+    #[arg(long)]
+    types_file: Option<PathBuf>,
+
     #[command(subcommand)]
     command: Option<Command>,
 }
 
-#[derive(Debug, serde::Serialize)]
-struct Finding {
-    path: String,
-    line: usize,
-    snippet: String,
-    matched_rules: Vec<String>,
-    tags: Vec<String>,
-    confidence: f64,
+#[derive(Debug, serde::Serialize, Clone)]
+pub(crate) struct Finding {
+    pub(crate) path: String,
+    pub(crate) line: usize,
+    pub(crate) snippet: String,
+    pub(crate) matched_rules: Vec<String>,
+    pub(crate) tags: Vec<String>,
+    pub(crate) confidence: f64,
 }
 
 #[derive(Debug, Deserialize)]
@@ -129,17 +246,27 @@ struct Config {
     entropy_threshold: Option<f64>,
 }
 
-fn default_patterns() -> Vec<rules::Rule> {
+pub(crate) fn default_patterns() -> Vec<rules::Rule> {
     rules::load_default_rules()
 }
 
-fn scan_file(path: &std::path::Path, patterns: &[rules::Rule]) -> Vec<Finding> {
+/// Scan a file's raw bytes, falling back to `scan_binary` for non-UTF-8 content.
+pub(crate) fn scan_file(path: &std::path::Path, patterns: &[rules::Rule], min_string_len: usize) -> Vec<Finding> {
+    let data = match fs::read(path) {
+        Ok(d) => d,
+        Err(_) => return Vec::new(),
+    };
+    let label = path.to_string_lossy();
+    match std::str::from_utf8(&data) {
+        Ok(content) => scan_text(&label, content, patterns),
+        Err(_) => scan_binary(&label, &data, patterns, min_string_len),
+    }
+}
+
+/// Run line-oriented rule matching over already-decoded text.
+pub(crate) fn scan_text(path_label: &str, content: &str, patterns: &[rules::Rule]) -> Vec<Finding> {
     use std::collections::HashSet as StdHashSet;
     let mut findings = Vec::new();
-    let content = match fs::read_to_string(path) {
-        Ok(s) => s,
-        Err(_) => return findings,
-    };
 
     // aggregate matches per (line, snippet)
     let mut map: HashMap<(usize, String), (Vec<String>, StdHashSet<String>, Vec<f64>)> = HashMap::new();
@@ -147,7 +274,7 @@ fn scan_file(path: &std::path::Path, patterns: &[rules::Rule]) -> Vec<Finding> {
     for (i, line) in content.lines().enumerate() {
         let snippet = line.trim().to_string();
         for rule in patterns.iter() {
-            if rule.regex.is_match(line) {
+            if rule.regex.is_match(line.as_bytes()) {
                 let key = (i + 1, snippet.clone());
                 let entry = map.entry(key).or_insert((Vec::new(), StdHashSet::new(), Vec::new()));
                 entry.0.push(rule.name.clone());
@@ -164,7 +291,7 @@ fn scan_file(path: &std::path::Path, patterns: &[rules::Rule]) -> Vec<Finding> {
         let combined = 1.0 - prod;
         let tags: Vec<String> = tagset.into_iter().collect();
         findings.push(Finding {
-            path: path.to_string_lossy().to_string(),
+            path: path_label.to_string(),
             line,
             snippet,
             matched_rules: names,
@@ -176,15 +303,58 @@ fn scan_file(path: &std::path::Path, patterns: &[rules::Rule]) -> Vec<Finding> {
     findings
 }
 
+/// Run rule matching over binary content by extracting printable-ASCII runs
+/// and matching rules against each; a run's byte offset stands in for
+/// `Finding::line`.
+pub(crate) fn scan_binary(path_label: &str, data: &[u8], patterns: &[rules::Rule], min_string_len: usize) -> Vec<Finding> {
+    use std::collections::HashSet as StdHashSet;
+    let mut findings = Vec::new();
+
+    for (offset, candidate) in strings::extract_strings(data, min_string_len) {
+        let mut names = Vec::new();
+        let mut tagset: StdHashSet<String> = StdHashSet::new();
+        let mut confidences = Vec::new();
+        for rule in patterns.iter() {
+            if rule.regex.is_match(candidate.as_bytes()) {
+                names.push(rule.name.clone());
+                for t in &rule.tags { tagset.insert(t.clone()); }
+                confidences.push(rule.confidence);
+            }
+        }
+        if names.is_empty() { continue }
 
-fn should_ignore(path: &str, ignores: &HashSet<String>) -> bool {
-    for ig in ignores.iter() {
-        if path.contains(ig) { return true }
+        let mut prod = 1.0f64;
+        for c in confidences { prod *= 1.0 - c; }
+        let combined = 1.0 - prod;
+        findings.push(Finding {
+            path: path_label.to_string(),
+            line: offset,
+            snippet: candidate,
+            matched_rules: names,
+            tags: tagset.into_iter().collect(),
+            confidence: combined,
+        });
     }
-    false
+
+    findings
 }
 
-fn scan_git_history(repo_path: &std::path::Path, patterns: &[rules::Rule], ignores: &HashSet<String>, entropy_threshold: f64) -> Vec<Finding> {
+
+/// Scan git history incrementally: for each commit, diff against its first
+/// parent (or an empty tree for the root commit) so only blobs the commit
+/// actually introduced or changed are visited, and skip any blob OID already
+/// scanned earlier in the walk so identical content is scanned once across
+/// history. `since`/`until` bound the revwalk the same way `git log A..B`
+/// does; with neither set the whole history reachable from HEAD is walked.
+fn scan_git_history(
+    repo_path: &std::path::Path,
+    patterns: &[rules::Rule],
+    ignores: &IgnoreMatcher,
+    entropy_threshold: f64,
+    min_string_len: usize,
+    since: Option<&str>,
+    until: Option<&str>,
+) -> Vec<Finding> {
     use std::collections::HashSet as StdHashSet;
     let mut findings = Vec::new();
     let repo = match Repository::discover(repo_path) {
@@ -196,8 +366,19 @@ fn scan_git_history(repo_path: &std::path::Path, patterns: &[rules::Rule], ignor
         Ok(rw) => rw,
         Err(_) => return findings,
     };
+    // oldest-first, so the first commit to introduce a blob is the one that
+    // ends up attributed to it below
+    if revwalk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::REVERSE).is_err() { return findings }
+
+    let pushed = match (since, until) {
+        (Some(s), Some(u)) => revwalk.push_range(&format!("{}..{}", s, u)),
+        (Some(s), None) => revwalk.push_range(&format!("{}..HEAD", s)),
+        (None, Some(u)) => repo.revparse_single(u).and_then(|o| revwalk.push(o.id())),
+        (None, None) => revwalk.push_head(),
+    };
+    if pushed.is_err() { return findings }
 
-    if revwalk.push_head().is_err() { return findings }
+    let mut scanned_blobs: StdHashSet<git2::Oid> = StdHashSet::new();
 
     for oid in revwalk.flatten() {
         let commit = match repo.find_commit(oid) {
@@ -210,64 +391,175 @@ fn scan_git_history(repo_path: &std::path::Path, patterns: &[rules::Rule], ignor
             Err(_) => continue,
         };
 
-        let mut stack = vec![tree];
-        while let Some(tree) = stack.pop() {
-            for entry in tree.iter() {
-                if let Some(name) = entry.name() {
-                    match entry.kind() {
-                        Some(git2::ObjectType::Blob) => {
-                            if should_ignore(name, ignores) { continue }
-                            let oid = entry.id();
-                            if let Ok(blob) = repo.find_blob(oid) {
-                                if let Ok(content) = std::str::from_utf8(blob.content()) {
-                                    // aggregate per blob by (line, snippet)
-                                    let mut map: HashMap<(usize, String), (Vec<String>, StdHashSet<String>, Vec<f64>)> = HashMap::new();
-                                    for rule in patterns.iter() {
-                                        for (i, line) in content.lines().enumerate() {
-                                            if rule.regex.is_match(line) {
-                                                let ent = shannon_entropy(line);
-                                                if ent >= entropy_threshold {
-                                                    let key = (i + 1, line.trim().to_string());
-                                                    let entry = map.entry(key).or_insert((Vec::new(), StdHashSet::new(), Vec::new()));
-                                                    entry.0.push(rule.name.clone());
-                                                    for t in &rule.tags { entry.1.insert(t.clone()); }
-                                                    entry.2.push(rule.confidence);
-                                                }
-                                            }
-                                        }
-                                    }
-                                    for ((line, snippet), (names, tagset, confidences)) in map {
-                                        let mut prod = 1.0f64;
-                                        for c in confidences { prod *= 1.0 - c; }
-                                        let combined = 1.0 - prod;
-                                        let tags: Vec<String> = tagset.into_iter().collect();
-                                        findings.push(Finding {
-                                            path: format!("git:{}:{}", commit.id(), name),
-                                            line,
-                                            snippet,
-                                            matched_rules: names,
-                                            tags,
-                                            confidence: combined,
-                                        });
-                                    }
-                                }
-                            }
-                        }
-                        Some(git2::ObjectType::Tree) => {
-                            if let Ok(obj) = entry.to_object(&repo) {
-                                if let Ok(t) = obj.peel_to_tree() {
-                                    stack.push(t);
-                                }
-                            }
-                        }
-                        _ => {}
+        let parent_tree = commit.parent(0).ok().and_then(|p| p.tree().ok());
+        let diff = match repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None) {
+            Ok(d) => d,
+            Err(_) => continue,
+        };
+
+        for delta in diff.deltas() {
+            if !matches!(
+                delta.status(),
+                git2::Delta::Added | git2::Delta::Modified | git2::Delta::Copied | git2::Delta::Renamed
+            ) {
+                continue;
+            }
+            let new_file = delta.new_file();
+            let blob_oid = new_file.id();
+            if blob_oid.is_zero() || !scanned_blobs.insert(blob_oid) { continue }
+            let rel_path = match new_file.path() {
+                Some(p) => p.to_string_lossy().replace('\\', "/"),
+                None => continue,
+            };
+            if ignores.is_ignored(&rel_path) { continue }
+
+            if let Ok(blob) = repo.find_blob(blob_oid) {
+                let label = format!("git:{}:{}", commit.id(), rel_path);
+                let content = blob.content();
+                let raw = match std::str::from_utf8(content) {
+                    Ok(text) => scan_text(&label, text, patterns),
+                    Err(_) => scan_binary(&label, content, patterns, min_string_len),
+                };
+                findings.extend(raw.into_iter().filter(|f| shannon_entropy(&f.snippet) >= entropy_threshold));
+            }
+        }
+    }
+
+    findings
+}
+
+/// Load `ignore` patterns and the entropy threshold from the user config
+/// file, an `--ignore-file`, and an `--entropy-threshold` override, in that
+/// order of precedence. Shared by the `Scan` and `Watch` commands so both
+/// honor the same config.
+pub(crate) fn load_scan_settings(cli_entropy: Option<f64>, cli_ignore: &Option<PathBuf>) -> (Vec<String>, f64) {
+    let mut ignores: Vec<String> = Vec::new();
+    let mut entropy_threshold = 3.5f64; // default
+    if let Some(cfg_dir) = dirs_next::config_dir() {
+        let cfg = cfg_dir.join("lss").join("config.toml");
+        if cfg.exists() {
+            if let Ok(mut s) = fs::File::open(&cfg) {
+                let mut buf = String::new();
+                if s.read_to_string(&mut buf).is_ok() {
+                    if let Ok(c) = toml::from_str::<Config>(&buf) {
+                        if let Some(v) = c.ignore { ignores.extend(v) }
+                        if let Some(e) = c.entropy_threshold { entropy_threshold = e }
                     }
                 }
             }
         }
     }
+    if let Some(e) = cli_entropy { entropy_threshold = e }
+    if let Some(ignf) = cli_ignore {
+        if let Ok(s) = fs::read_to_string(ignf) {
+            for line in s.lines() { let t = line.trim(); if !t.is_empty() { ignores.push(t.to_string()); } }
+        }
+    }
+    (ignores, entropy_threshold)
+}
 
-    findings
+/// Read the patterns in a root `.lssignore`, if one exists under `path`.
+pub(crate) fn load_root_lssignore(path: &std::path::Path) -> Vec<String> {
+    let mut ignore_fileset = Vec::new();
+    let ignore_path = path.join(".lssignore");
+    if ignore_path.exists() {
+        if let Ok(s) = fs::read_to_string(&ignore_path) {
+            for line in s.lines() { let t = line.trim(); if !t.is_empty() { ignore_fileset.push(t.to_string()); } }
+        }
+    }
+    ignore_fileset
+}
+
+/// Build the effective ignore matcher for every directory that appears among
+/// `file_paths`: config/`.lssignore`-root patterns plus each directory's own
+/// `.lssignore`, inherited root-first down to leaf directories so a deeper
+/// pattern can `!`-negate one set higher up. Each directory's `.lssignore` is
+/// read and compiled exactly once, however many files it contains, by
+/// building the map shallowest-directory-first and cloning the parent's
+/// already-compiled matcher.
+pub(crate) fn build_dir_matchers<'a>(
+    root: &std::path::Path,
+    file_paths: impl IntoIterator<Item = &'a std::path::Path>,
+    config_ignores: &IgnoreMatcher,
+    ignore_fileset: &[String],
+) -> HashMap<std::path::PathBuf, IgnoreMatcher> {
+    let mut dirs: HashSet<std::path::PathBuf> = HashSet::new();
+    for file_path in file_paths {
+        let mut dir = match file_path.parent() {
+            Some(p) => p,
+            None => continue,
+        };
+        loop {
+            if !dirs.insert(dir.to_path_buf()) || dir == root {
+                break;
+            }
+            match dir.parent() {
+                Some(up) if up.starts_with(root) => dir = up,
+                _ => break,
+            }
+        }
+    }
+    dirs.insert(root.to_path_buf());
+
+    let mut ordered: Vec<std::path::PathBuf> = dirs.into_iter().collect();
+    ordered.sort_by_key(|d| d.components().count());
+
+    let mut base = config_ignores.clone();
+    base.extend(ignore_fileset);
+
+    let mut cache: HashMap<std::path::PathBuf, IgnoreMatcher> = HashMap::new();
+    for dir in ordered {
+        let mut matcher = if dir == root {
+            base.clone()
+        } else {
+            match dir.parent().and_then(|p| cache.get(p)) {
+                Some(parent_matcher) => parent_matcher.clone(),
+                None => base.clone(),
+            }
+        };
+        if dir != root {
+            let lp = dir.join(".lssignore");
+            if lp.exists() {
+                if let Ok(txt) = fs::read_to_string(&lp) {
+                    matcher.extend(txt.lines());
+                }
+            }
+        }
+        cache.insert(dir, matcher);
+    }
+    cache
+}
+
+/// Apply the `--min-confidence`/`--include-tags`/`--exclude-tags`/entropy
+/// filters shared by the `Scan` and `Watch` commands, in place.
+pub(crate) fn filter_findings(
+    findings: &mut Vec<Finding>,
+    min_confidence: Option<f64>,
+    include_tags_set: &Option<HashSet<String>>,
+    exclude_tags_set: &Option<HashSet<String>>,
+    entropy_threshold: f64,
+) {
+    findings.retain(|f| {
+        if f.confidence < min_confidence.unwrap_or(0.0) { return false }
+        if let Some(ex) = exclude_tags_set { for t in &f.tags { if ex.contains(t) { return false } } }
+        if let Some(inc) = include_tags_set { for t in &f.tags { if inc.contains(t) { return true } } ; return false }
+        true
+    });
+    findings.retain(|f| shannon_entropy(&f.snippet) >= entropy_threshold);
+}
+
+/// Print findings in the chosen `human`/`json` format, matching `Scan`'s output.
+pub(crate) fn print_findings(findings: &[Finding], format: &str) -> Result<()> {
+    if format == "json" {
+        println!("{}", serde_json::to_string_pretty(&findings)?);
+    } else {
+        for f in findings {
+            let rules_str = if f.matched_rules.is_empty() { "".to_string() } else { format!(" [{}]", f.matched_rules.join(",")) };
+            let tags = if f.tags.is_empty() { "".to_string() } else { format!(" tags={}", f.tags.join(",")) };
+            println!("{}:{}: {}{}{} conf={}", f.path, f.line, f.snippet, rules_str, tags, f.confidence);
+        }
+    }
+    Ok(())
 }
 
 fn main() -> Result<()> {
@@ -282,9 +574,15 @@ fn main() -> Result<()> {
             include_tags: cli.include_tags,
             exclude_tags: cli.exclude_tags,
             min_confidence: cli.min_confidence,
+            since: cli.since,
+            until: cli.until,
+            min_string_len: cli.min_string_len,
+            r#type: cli.r#type,
+            type_not: cli.type_not,
+            types_file: cli.types_file,
         }
     } else {
-        cli.command.unwrap_or(Command::Scan { path: PathBuf::from("."), format: "human".to_string(), entropy_threshold: None, ignore_file: None, rules_file: None, include_tags: None, exclude_tags: None, min_confidence: None })
+        cli.command.unwrap_or(Command::Scan { path: PathBuf::from("."), format: "human".to_string(), entropy_threshold: None, ignore_file: None, rules_file: None, include_tags: None, exclude_tags: None, min_confidence: None, since: None, until: None, min_string_len: 6, r#type: Vec::new(), type_not: Vec::new(), types_file: None })
     };
     let res: Result<()> = match command {
         Command::Rules { cmd } => {
@@ -312,7 +610,7 @@ fn main() -> Result<()> {
                 }
             }
         }
-        Command::Scan { path, format, entropy_threshold: cli_entropy, ignore_file: cli_ignore, rules_file: cli_rules, include_tags, exclude_tags, min_confidence } => {
+        Command::Scan { path, format, entropy_threshold: cli_entropy, ignore_file: cli_ignore, rules_file: cli_rules, include_tags, exclude_tags, min_confidence, since, until, min_string_len, r#type, type_not, types_file } => {
             // prepare patterns
             let mut patterns = default_patterns();
             if let Some(rf) = &cli_rules { let extra = rules::load_rules_from_file(rf); patterns.extend(extra); }
@@ -322,81 +620,57 @@ fn main() -> Result<()> {
             let exclude_tags_set: Option<HashSet<String>> = exclude_tags.map(|s| s.split(',').map(|t| t.trim().to_string()).collect());
 
             // load config
-            let mut ignores: HashSet<String> = HashSet::new();
-            let mut entropy_threshold = 3.5f64; // default
-            if let Some(cfg_dir) = dirs_next::config_dir() {
-                let cfg = cfg_dir.join("lss").join("config.toml");
-                if cfg.exists() {
-                    if let Ok(mut s) = fs::File::open(&cfg) {
-                        let mut buf = String::new();
-                        if s.read_to_string(&mut buf).is_ok() {
-                            if let Ok(c) = toml::from_str::<Config>(&buf) {
-                                if let Some(v) = c.ignore { for it in v { ignores.insert(it); } }
-                                if let Some(e) = c.entropy_threshold { entropy_threshold = e }
-                            }
-                        }
-                    }
-                }
-            }
-            // CLI overrides
-            if let Some(e) = cli_entropy { entropy_threshold = e }
-            if let Some(ignf) = &cli_ignore { if let Ok(s) = fs::read_to_string(ignf) { for line in s.lines() { let t = line.trim(); if !t.is_empty() { ignores.insert(t.to_string()); } } } }
+            let (ignores, entropy_threshold) = load_scan_settings(cli_entropy, &cli_ignore);
+            let mut config_ignores = IgnoreMatcher::new();
+            config_ignores.extend(&ignores);
+
+            // prepare type filters
+            let mut type_defs = types::default_types();
+            if let Some(tf) = &types_file { type_defs.extend(types::load_types_from_file(tf)); }
+            let type_registry = types::TypeRegistry::new(type_defs);
+            type_registry.validate(&r#type)?;
+            type_registry.validate(&type_not)?;
 
             // prepare walk
             let walker = WalkDir::new(&path).into_iter();
             let entries: Vec<_> = walker.filter_map(|e| e.ok()).filter(|e| e.file_type().is_file()).collect();
 
             // load root .lssignore
-            let mut ignore_fileset: HashSet<String> = HashSet::new();
-            let ignore_path = path.join(".lssignore");
-            if ignore_path.exists() {
-                if let Ok(s) = fs::read_to_string(&ignore_path) {
-                    for line in s.lines() { let t = line.trim(); if !t.is_empty() { ignore_fileset.insert(t.to_string()); } }
-                }
-            }
+            let ignore_fileset = load_root_lssignore(&path);
+
+            // compile each directory's ignore matcher once, up front, rather than
+            // re-reading and recompiling it for every file it contains
+            let dir_matchers = build_dir_matchers(&path, entries.iter().map(|e| e.path()), &config_ignores, &ignore_fileset);
 
             // perform scanning (files + git history)
             let results_files: Vec<Finding> = entries.par_iter().flat_map(|entry| {
                 let p = entry.path();
-                let s = p.to_string_lossy().to_string();
-                // combine config ignores and .lssignore
-                let mut combined_ignores = ignores.clone();
-                for it in ignore_fileset.iter() { combined_ignores.insert(it.clone()); }
-                // per-repo ignores up the tree
-                let mut repo_ignores = HashSet::new();
-                if let Some(parent) = p.parent() {
-                    let mut dir = parent;
-                    while dir.starts_with(&path) {
-                        let lp = dir.join(".lssignore");
-                        if lp.exists() {
-                            if let Ok(txt) = fs::read_to_string(&lp) {
-                                for line in txt.lines() { let t = line.trim(); if !t.is_empty() { repo_ignores.insert(t.to_string()); } }
-                            }
-                        }
-                        if let Some(up) = dir.parent() { dir = up } else { break }
-                    }
-                }
-                for it in repo_ignores { combined_ignores.insert(it); }
-                if should_ignore(&s, &combined_ignores) { return Vec::new() }
-                let mut r = scan_file(&p, &patterns);
-                // apply entropy and tag/confidence filters
-                r.retain(|f| {
-                    if f.confidence < min_confidence.unwrap_or(0.0) { return false }
-                    if let Some(ex) = &exclude_tags_set { for t in &f.tags { if ex.contains(t) { return false } } }
-                    if let Some(inc) = &include_tags_set { for t in &f.tags { if inc.contains(t) { return true } } ; return false }
-                    true
-                });
-                r.retain(|f| shannon_entropy(&f.snippet) >= entropy_threshold);
+                let file_name = p.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+                if !type_registry.passes(&file_name, &r#type, &type_not) { return Vec::new() }
+                let empty_matcher = IgnoreMatcher::new();
+                let matcher = p.parent().and_then(|d| dir_matchers.get(d)).unwrap_or(&empty_matcher);
+                let rel_path = p.strip_prefix(&path).unwrap_or(p).to_string_lossy().replace('\\', "/");
+                if matcher.is_ignored(&rel_path) { return Vec::new() }
+                let mut r = if archive::is_archive(p) {
+                    archive::scan_archive(p, &patterns, matcher, min_string_len)
+                } else {
+                    scan_file(p, &patterns, min_string_len)
+                };
+                filter_findings(&mut r, min_confidence, &include_tags_set, &exclude_tags_set, entropy_threshold);
                 r
             }).collect();
 
-            // scan git history for repos inside the path
+            // scan git history for the path itself, plus any repos in its immediate subdirectories
             let mut results_git = Vec::new();
+            if path.join(".git").exists() {
+                let mut g = scan_git_history(&path, &patterns, &config_ignores, entropy_threshold, min_string_len, since.as_deref(), until.as_deref());
+                results_git.append(&mut g);
+            }
             if let Ok(entries) = fs::read_dir(&path) {
                 for e in entries.flatten() {
                     let p = e.path();
                     if p.join(".git").exists() {
-                        let mut g = scan_git_history(&p, &patterns, &ignores, entropy_threshold);
+                        let mut g = scan_git_history(&p, &patterns, &config_ignores, entropy_threshold, min_string_len, since.as_deref(), until.as_deref());
                         results_git.append(&mut g);
                     }
                 }
@@ -405,20 +679,103 @@ fn main() -> Result<()> {
             let mut results = results_files;
             results.extend(results_git);
 
-            if format == "json" {
-                println!("{}", serde_json::to_string_pretty(&results)?);
-            } else {
-                for f in &results {
-                    let rules_str = if f.matched_rules.is_empty() { "".to_string() } else { format!(" [{}]", f.matched_rules.join(",")) };
-                    let tags = if f.tags.is_empty() { "".to_string() } else { format!(" tags={}", f.tags.join(",")) };
-                    println!("{}:{}: {}{}{} conf={}", f.path, f.line, f.snippet, rules_str, tags, f.confidence);
-                }
+            print_findings(&results, &format)?;
+            if format != "json" {
                 println!("\nFound {} potential secrets", results.len());
             }
 
             Ok(())
         }
+        Command::Watch { path, format, entropy_threshold: cli_entropy, ignore_file: cli_ignore, rules_file: cli_rules, include_tags, exclude_tags, min_confidence, min_string_len } => {
+            watch::run(watch::WatchArgs {
+                path,
+                format,
+                cli_entropy,
+                cli_ignore,
+                cli_rules,
+                include_tags,
+                exclude_tags,
+                min_confidence,
+                min_string_len,
+            })
+        }
+        Command::Types { cmd } => {
+            match cmd {
+                TypesCmd::List { query, json, page, per_page, types_file } => {
+                    let mut defs = types::default_types();
+                    if let Some(tf) = &types_file { defs.extend(types::load_types_from_file(tf)); }
+                    if let Some(q) = &query {
+                        defs = defs.into_iter().filter(|t| t.name.contains(q)).collect();
+                    }
+                    #[derive(Clone, serde::Serialize)]
+                    struct TypeView { name: String, globs: Vec<String> }
+                    let views: Vec<TypeView> = defs.into_iter().map(|t| TypeView { name: t.name, globs: t.globs }).collect();
+                    let total = views.len();
+                    let start = (page.saturating_sub(1)).saturating_mul(per_page);
+                    let end = std::cmp::min(start + per_page, total);
+                    let slice: Vec<TypeView> = if start < total { views[start..end].to_vec() } else { Vec::new() };
+                    if json {
+                        let out = serde_json::json!({"total": total, "page": page, "per_page": per_page, "types": slice});
+                        println!("{}", serde_json::to_string_pretty(&out)?);
+                    } else {
+                        for t in &slice { println!("{} :: {}", t.name, t.globs.join(",")); }
+                        println!("Showing {}-{} of {}", start+1, end, total);
+                    }
+                    Ok(())
+                }
+            }
+        }
     };
 
     res
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use git2::Signature;
+
+    fn commit_file(repo: &Repository, rel_path: &str, content: &str, message: &str) -> git2::Oid {
+        std::fs::write(repo.workdir().unwrap().join(rel_path), content).unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(std::path::Path::new(rel_path)).unwrap();
+        index.write().unwrap();
+        let tree_oid = index.write_tree().unwrap();
+        let tree = repo.find_tree(tree_oid).unwrap();
+        let sig = Signature::now("test", "test@example.com").unwrap();
+        let parents: Vec<git2::Commit> = repo.head().ok().and_then(|h| h.peel_to_commit().ok()).into_iter().collect();
+        let parent_refs: Vec<&git2::Commit> = parents.iter().collect();
+        repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parent_refs).unwrap()
+    }
+
+    #[test]
+    fn scan_git_history_respects_since_until_and_finds_root_repo() {
+        let dir = std::env::temp_dir().join(format!("lss-test-repo-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let repo = Repository::init(&dir).unwrap();
+        commit_file(&repo, "a.env", "PLAIN=notasecret", "add a.env");
+        let second = commit_file(&repo, "b.env", "TOKEN=sk_live_abcdef123456", "add b.env");
+        commit_file(&repo, "c.env", "TOKEN=sk_live_other999999", "add c.env");
+
+        let patterns = parse_rules_for_test();
+        let ignores = IgnoreMatcher::new();
+
+        // called with the repo root itself (the common `lss scan .` case), not a parent dir
+        let all = scan_git_history(&dir, &patterns, &ignores, 0.0, 6, None, None);
+        let until_second = scan_git_history(&dir, &patterns, &ignores, 0.0, 6, None, Some(&second.to_string()));
+        let since_second = scan_git_history(&dir, &patterns, &ignores, 0.0, 6, Some(&second.to_string()), None);
+
+        std::fs::remove_dir_all(&dir).ok();
+
+        assert_eq!(all.len(), 2);
+        assert_eq!(until_second.len(), 1);
+        assert!(until_second[0].path.contains("b.env"));
+        assert_eq!(since_second.len(), 1);
+        assert!(since_second[0].path.contains("c.env"));
+    }
+
+    fn parse_rules_for_test() -> Vec<rules::Rule> {
+        rules::parse_rules("Secret::sk_live_[0-9a-zA-Z]+::test,0.9")
+    }
+}
@@ -0,0 +1,55 @@
+/// `strings`-style extraction: pull out runs of printable ASCII of at least
+/// `min_len` bytes from arbitrary binary data, each paired with the byte
+/// offset it starts at. Used as a pre-pass for binary content where
+/// line-oriented scanning doesn't apply.
+pub(crate) fn extract_strings(data: &[u8], min_len: usize) -> Vec<(usize, String)> {
+    let mut out = Vec::new();
+    let mut start = 0usize;
+    let mut run: Vec<u8> = Vec::new();
+    let mut in_run = false;
+
+    for (i, &b) in data.iter().enumerate() {
+        if (0x20..=0x7e).contains(&b) {
+            if !in_run {
+                start = i;
+                in_run = true;
+            }
+            run.push(b);
+        } else if in_run {
+            if run.len() >= min_len {
+                out.push((start, String::from_utf8_lossy(&run).into_owned()));
+            }
+            run.clear();
+            in_run = false;
+        }
+    }
+    if in_run && run.len() >= min_len {
+        out.push((start, String::from_utf8_lossy(&run).into_owned()));
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_runs_at_or_above_min_len() {
+        let data = b"\x00\x01abcdef\x02\x03hi\x04ghijklmno\x05";
+        let found = extract_strings(data, 6);
+        assert_eq!(found, vec![(2, "abcdef".to_string()), (13, "ghijklmno".to_string())]);
+    }
+
+    #[test]
+    fn empty_input_yields_nothing() {
+        assert!(extract_strings(&[], 6).is_empty());
+    }
+
+    #[test]
+    fn zero_min_len_does_not_duplicate_empty_runs() {
+        let data = b"\x00\x00\x00ab\x00";
+        let found = extract_strings(data, 0);
+        assert_eq!(found, vec![(3, "ab".to_string())]);
+    }
+}
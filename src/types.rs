@@ -0,0 +1,152 @@
+use anyhow::{bail, Result};
+use regex::Regex;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::ignore::compile_glob;
+
+/// One named file type: a set of globs matched against a bare file name
+/// (not the full path), mirroring ripgrep's `--type`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeDef {
+    pub name: String,
+    pub globs: Vec<String>,
+}
+
+/// The built-in, lexicographically-sorted set of named file types.
+pub fn default_types() -> Vec<TypeDef> {
+    let raw: &[(&str, &[&str])] = &[
+        ("config", &["*.conf", "*.cfg", "*.config"]),
+        ("docker", &["Dockerfile", "*.dockerfile"]),
+        ("env", &[".env", "*.env"]),
+        ("go", &["*.go"]),
+        ("java", &["*.java"]),
+        ("js", &["*.js", "*.jsx"]),
+        ("json", &["*.json"]),
+        ("md", &["*.md"]),
+        ("pem", &["*.pem", "*.key"]),
+        ("php", &["*.php"]),
+        ("py", &["*.py"]),
+        ("rb", &["*.rb"]),
+        ("rs", &["*.rs"]),
+        ("sh", &["*.sh", "*.bash"]),
+        ("toml", &["*.toml"]),
+        ("ts", &["*.ts", "*.tsx"]),
+        ("txt", &["*.txt"]),
+        ("yaml", &["*.yaml", "*.yml"]),
+    ];
+    let mut defs: Vec<TypeDef> = raw
+        .iter()
+        .map(|(name, globs)| TypeDef {
+            name: name.to_string(),
+            globs: globs.iter().map(|g| g.to_string()).collect(),
+        })
+        .collect();
+    defs.sort_by(|a, b| a.name.cmp(&b.name));
+    defs
+}
+
+/// Parse `name:glob,glob` lines (one type per line) as read from a custom
+/// type-definitions file.
+pub fn parse_types(s: &str) -> Vec<TypeDef> {
+    let mut v = Vec::new();
+    for line in s.lines() {
+        let l = line.trim();
+        if l.is_empty() || l.starts_with('#') { continue }
+        if let Some((name, globs)) = l.split_once(':') {
+            let name = name.trim();
+            let globs: Vec<String> = globs.split(',').map(|g| g.trim().to_string()).filter(|g| !g.is_empty()).collect();
+            if !name.is_empty() && !globs.is_empty() {
+                v.push(TypeDef { name: name.to_string(), globs });
+            }
+        }
+    }
+    v
+}
+
+pub fn load_types_from_file(path: &PathBuf) -> Vec<TypeDef> {
+    if let Ok(s) = fs::read_to_string(path) { parse_types(&s) } else { Vec::new() }
+}
+
+/// Compiled type definitions, used to test a file name against `--type`/
+/// `--type-not` filters.
+pub struct TypeRegistry {
+    compiled: HashMap<String, Vec<Regex>>,
+}
+
+impl TypeRegistry {
+    pub fn new(defs: Vec<TypeDef>) -> Self {
+        let mut compiled = HashMap::new();
+        for def in defs {
+            let regexes = def.globs.iter().filter_map(|g| compile_glob(g)).collect();
+            compiled.insert(def.name, regexes);
+        }
+        TypeRegistry { compiled }
+    }
+
+    fn matches(&self, file_name: &str, type_name: &str) -> bool {
+        self.compiled
+            .get(type_name)
+            .map(|regexes| regexes.iter().any(|r| r.is_match(file_name)))
+            .unwrap_or(false)
+    }
+
+    /// Does `file_name` satisfy the `--type`/`--type-not` filters? An empty
+    /// `include` list passes everything not excluded; a non-empty one
+    /// requires at least one match.
+    pub fn passes(&self, file_name: &str, include: &[String], exclude: &[String]) -> bool {
+        if exclude.iter().any(|t| self.matches(file_name, t)) {
+            return false;
+        }
+        include.is_empty() || include.iter().any(|t| self.matches(file_name, t))
+    }
+
+    /// Error out on an unrecognized `--type`/`--type-not` name instead of
+    /// silently matching nothing, mirroring ripgrep's `--type` validation.
+    pub fn validate(&self, names: &[String]) -> Result<()> {
+        for name in names {
+            if !self.compiled.contains_key(name) {
+                bail!("unknown file type `{}` (see `lss types list`)", name);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_types_are_sorted() {
+        let defs = default_types();
+        let mut sorted = defs.clone();
+        sorted.sort_by(|a, b| a.name.cmp(&b.name));
+        assert_eq!(defs.iter().map(|d| &d.name).collect::<Vec<_>>(), sorted.iter().map(|d| &d.name).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn registry_filters_by_type() {
+        let registry = TypeRegistry::new(default_types());
+        assert!(registry.passes("main.py", &["py".to_string()], &[]));
+        assert!(!registry.passes("main.py", &["js".to_string()], &[]));
+        assert!(!registry.passes("main.py", &[], &["py".to_string()]));
+        assert!(registry.passes("main.py", &[], &[]));
+    }
+
+    #[test]
+    fn validate_rejects_unknown_type_name() {
+        let registry = TypeRegistry::new(default_types());
+        assert!(registry.validate(&["py".to_string()]).is_ok());
+        assert!(registry.validate(&["pyy".to_string()]).is_err());
+    }
+
+    #[test]
+    fn custom_types_parse() {
+        let defs = parse_types("logs:*.log,*.out\n# comment\nbad-line\n");
+        assert_eq!(defs.len(), 1);
+        assert_eq!(defs[0].name, "logs");
+        assert_eq!(defs[0].globs, vec!["*.log".to_string(), "*.out".to_string()]);
+    }
+}
@@ -0,0 +1,92 @@
+use anyhow::Result;
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::time::Duration;
+
+use crate::ignore::IgnoreMatcher;
+use crate::{build_dir_matchers, default_patterns, filter_findings, load_root_lssignore, load_scan_settings, print_findings, rules, scan_file};
+
+/// Coalesce bursts of editor-save events (write, then rename-into-place,
+/// then a metadata touch) into a single rescan per quiet period.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Parameters for `lss watch`, mirroring the subset of `Scan`'s flags that
+/// apply to a live rescan loop (no git-history bounds).
+pub(crate) struct WatchArgs {
+    pub(crate) path: PathBuf,
+    pub(crate) format: String,
+    pub(crate) cli_entropy: Option<f64>,
+    pub(crate) cli_ignore: Option<PathBuf>,
+    pub(crate) cli_rules: Option<PathBuf>,
+    pub(crate) include_tags: Option<String>,
+    pub(crate) exclude_tags: Option<String>,
+    pub(crate) min_confidence: Option<f64>,
+    pub(crate) min_string_len: usize,
+}
+
+/// Watch `args.path` and rescan changed files as they are written.
+pub(crate) fn run(args: WatchArgs) -> Result<()> {
+    let mut patterns = default_patterns();
+    if let Some(rf) = &args.cli_rules {
+        patterns.extend(rules::load_rules_from_file(rf));
+    }
+
+    let include_tags_set: Option<HashSet<String>> =
+        args.include_tags.as_ref().map(|s| s.split(',').map(|t| t.trim().to_string()).collect());
+    let exclude_tags_set: Option<HashSet<String>> =
+        args.exclude_tags.as_ref().map(|s| s.split(',').map(|t| t.trim().to_string()).collect());
+
+    let (ignores, entropy_threshold) = load_scan_settings(args.cli_entropy, &args.cli_ignore);
+    let mut config_ignores = IgnoreMatcher::new();
+    config_ignores.extend(&ignores);
+    let ignore_fileset = load_root_lssignore(&args.path);
+
+    let (tx, rx) = channel::<notify::Result<Event>>();
+    let mut watcher: RecommendedWatcher = notify::recommended_watcher(move |res| {
+        let _ = tx.send(res);
+    })?;
+    watcher.watch(&args.path, RecursiveMode::Recursive)?;
+
+    eprintln!("Watching {} for changes (Ctrl+C to stop)...", args.path.display());
+
+    let mut pending: HashSet<PathBuf> = HashSet::new();
+    loop {
+        match rx.recv_timeout(DEBOUNCE) {
+            Ok(Ok(event)) => {
+                for p in event.paths {
+                    if p.is_file() {
+                        pending.insert(p);
+                    }
+                }
+            }
+            Ok(Err(_)) => {}
+            Err(RecvTimeoutError::Timeout) => {
+                if pending.is_empty() {
+                    continue;
+                }
+                let batch: Vec<PathBuf> = pending.drain().collect();
+                let dir_matchers = build_dir_matchers(&args.path, batch.iter().map(|p| p.as_path()), &config_ignores, &ignore_fileset);
+                let empty_matcher = IgnoreMatcher::new();
+                let mut findings = Vec::new();
+                for p in &batch {
+                    let matcher = p.parent().and_then(|d| dir_matchers.get(d)).unwrap_or(&empty_matcher);
+                    let rel_path = p.strip_prefix(&args.path).unwrap_or(p).to_string_lossy().replace('\\', "/");
+                    if matcher.is_ignored(&rel_path) {
+                        continue;
+                    }
+                    let mut r = scan_file(p, &patterns, args.min_string_len);
+                    filter_findings(&mut r, args.min_confidence, &include_tags_set, &exclude_tags_set, entropy_threshold);
+                    findings.extend(r);
+                }
+                if !findings.is_empty() {
+                    print_findings(&findings, &args.format)?;
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+
+    Ok(())
+}